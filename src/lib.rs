@@ -1,12 +1,15 @@
 
-use sysinfo::{Disks, System};
+use std::fs;
+use std::time::Duration;
+
+use sysinfo::{Components, Disks, Networks, System};
 
 
 /// 获取本机CPU核数
 /// 
 /// # Examples
-/// 
-/// ```
+///
+/// ```no_run
 /// use sysinfo::{Components, Disks, Networks, System};
 /// let mut sys: System = System::new_all();
 /// assert_eq!(collection_learning::total_num_cpus(&mut sys), Ok(16));
@@ -25,11 +28,11 @@ pub fn total_num_cpus(sys: &mut System) -> Result<usize, String> {
 /// 获取本机可用核数
 /// 
 /// # Examples
-/// 
-/// ```
+///
+/// ```no_run
 /// use sysinfo::{Components, Disks, Networks, System};
 /// let mut sys: System = System::new_all();
-/// assert_eq!(collection_learning::used_num_cpus(&mut sys), Ok(16));
+/// assert_eq!(collection_learning::availabe_num_cpus(&mut sys), Ok(16));
 /// ```
 pub fn availabe_num_cpus(sys: &mut System) -> Result<usize, String> {
     sys.refresh_cpu_all();
@@ -50,8 +53,8 @@ pub fn availabe_num_cpus(sys: &mut System) -> Result<usize, String> {
 /// 获取本机内存总量，单位字节
 /// 
 /// # Examples
-/// 
-/// ```
+///
+/// ```no_run
 /// use sysinfo::{Components, Disks, Networks, System};
 /// let mut sys: System = System::new_all();
 /// assert_eq!(collection_learning::total_memory(&mut sys), Ok(16));
@@ -69,8 +72,8 @@ pub fn total_memory(sys: &mut System) -> Result<u64, String> {
 /// 获取本机内存可用量，单位字节
 /// 
 /// # Examples
-/// 
-/// ```
+///
+/// ```no_run
 /// use sysinfo::{Components, Disks, Networks, System};
 /// let mut sys: System = System::new_all();
 /// assert_eq!(collection_learning::available_memory(&mut sys), Ok(7233028096));
@@ -91,14 +94,13 @@ pub fn available_memory(sys: &mut System) -> Result<u64, String> {
 /// 获取本机磁盘总空间，单位字节
 /// 
 /// # Examples
-/// 
-/// ```
+///
+/// ```no_run
 /// use sysinfo::{Components, Disks, Networks, System};
 /// let mut sys: System = System::new_all();
 /// assert_eq!(collection_learning::total_space(&mut sys), Ok(1254954610688));
 /// ```
-
-pub fn total_space(sys: &mut System) -> Result<u64, String> {
+pub fn total_space(_sys: &mut System) -> Result<u64, String> {
     let disks: Disks = Disks::new_with_refreshed_list();
 
     if disks.is_empty() {
@@ -114,13 +116,13 @@ pub fn total_space(sys: &mut System) -> Result<u64, String> {
 /// 获取本机磁盘可用总空间，单位字节
 /// 
 /// # Examples
-/// 
-/// ```
+///
+/// ```no_run
 /// use sysinfo::{Components, Disks, Networks, System};
 /// let mut sys: System = System::new_all();
 /// assert_eq!(collection_learning::available_space(&mut sys), Ok(866847330304));
 /// ```
-pub fn available_space(sys: &mut System) -> Result<u64, String> {
+pub fn available_space(_sys: &mut System) -> Result<u64, String> {
     let disks: Disks = Disks::new_with_refreshed_list();
 
 
@@ -132,3 +134,755 @@ pub fn available_space(sys: &mut System) -> Result<u64, String> {
 
     Ok(total_available_space)
 }
+
+
+/// cgroup 的版本
+#[cfg(target_os = "linux")]
+enum CgroupVersion {
+    V1,
+    V2,
+}
+
+/// 按 CPU 控制文件探测当前系统使用的 cgroup 版本：存在 `cpu.max` 视为 v2，
+/// 否则存在 `cpu/cpu.cfs_quota_us` 视为 v1，两者都不存在时返回 `None`
+#[cfg(target_os = "linux")]
+fn detect_cgroup_version() -> Option<CgroupVersion> {
+    if std::path::Path::new("/sys/fs/cgroup/cpu.max").exists() {
+        Some(CgroupVersion::V2)
+    } else if std::path::Path::new("/sys/fs/cgroup/cpu/cpu.cfs_quota_us").exists() {
+        Some(CgroupVersion::V1)
+    } else {
+        None
+    }
+}
+
+/// 解析 cgroup v2 `cpu.max` 文件（`"quota period"`），未设置限额（`"max"`）
+/// 或解析失败时返回 `None`
+#[cfg(target_os = "linux")]
+fn parse_cgroup_v2_cpu_max(path: &str) -> Option<usize> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut parts = content.split_whitespace();
+    let quota = parts.next()?;
+    let period = parts.next()?;
+
+    if quota == "max" {
+        return None;
+    }
+
+    let quota: f64 = quota.parse().ok()?;
+    let period: f64 = period.parse().ok()?;
+
+    if period <= 0.0 {
+        return None;
+    }
+
+    Some((quota / period).ceil() as usize)
+}
+
+/// 解析 cgroup v1 的 `cpu.cfs_quota_us` / `cpu.cfs_period_us`，quota 为负数
+/// （未设置限额）或解析失败时返回 `None`
+#[cfg(target_os = "linux")]
+fn parse_cgroup_v1_cpu_quota(quota_path: &str, period_path: &str) -> Option<usize> {
+    let quota: i64 = fs::read_to_string(quota_path).ok()?.trim().parse().ok()?;
+    let period: i64 = fs::read_to_string(period_path).ok()?.trim().parse().ok()?;
+
+    if quota <= 0 || period <= 0 {
+        return None;
+    }
+
+    Some(((quota as f64) / (period as f64)).ceil() as usize)
+}
+
+/// 获取容器（cgroup）限额下实际可用的 CPU 核数
+///
+/// 宿主机的 `total_num_cpus`/`availabe_num_cpus` 反映的是物理机的核数，
+/// 在容器中运行时往往比 cgroup 配额大得多。本函数优先解析 cgroup v2 的
+/// `/sys/fs/cgroup/cpu.max`（`quota period`，有效核数为
+/// `ceil(quota / period)`），其次解析 cgroup v1 的
+/// `cpu.cfs_quota_us`/`cpu.cfs_period_us`；未设置限额（`"max"` 或负数
+/// quota）、非 Linux 平台或解析失败时回退到 `total_num_cpus`。
+///
+/// # Examples
+///
+/// ```
+/// use sysinfo::{Components, Disks, Networks, System};
+/// let mut sys: System = System::new_all();
+/// let _ = collection_learning::cgroup_available_cpus(&mut sys);
+/// ```
+#[cfg(target_os = "linux")]
+pub fn cgroup_available_cpus(sys: &mut System) -> Result<usize, String> {
+    match detect_cgroup_version() {
+        Some(CgroupVersion::V2) => {
+            if let Some(cpus) = parse_cgroup_v2_cpu_max("/sys/fs/cgroup/cpu.max") {
+                return Ok(cpus);
+            }
+        }
+        Some(CgroupVersion::V1) => {
+            if let Some(cpus) = parse_cgroup_v1_cpu_quota(
+                "/sys/fs/cgroup/cpu/cpu.cfs_quota_us",
+                "/sys/fs/cgroup/cpu/cpu.cfs_period_us",
+            ) {
+                return Ok(cpus);
+            }
+        }
+        None => {}
+    }
+
+    total_num_cpus(sys)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn cgroup_available_cpus(sys: &mut System) -> Result<usize, String> {
+    total_num_cpus(sys)
+}
+
+/// cgroup v1 `memory.limit_in_bytes` 用于表示"未设置限额"的哨兵值
+/// （内核按页大小（4096 字节）向下取整后的 `i64::MAX`）
+#[cfg(target_os = "linux")]
+const CGROUP_V1_MEMORY_UNLIMITED: u64 = 9_223_372_036_854_771_712;
+
+/// 解析 cgroup v2 的 `memory.max`/`memory.current`，未设置限额（`"max"`）
+/// 或解析失败时返回 `None`
+#[cfg(target_os = "linux")]
+fn parse_cgroup_v2_memory(max_path: &str, current_path: &str) -> Option<u64> {
+    let max = fs::read_to_string(max_path).ok()?;
+    let max = max.trim();
+
+    if max == "max" {
+        return None;
+    }
+
+    let max: u64 = max.parse().ok()?;
+    let current: u64 = fs::read_to_string(current_path).ok()?.trim().parse().ok()?;
+
+    Some(max.saturating_sub(current))
+}
+
+/// 解析 cgroup v1 的 `memory.limit_in_bytes`/`memory.usage_in_bytes`，达到或
+/// 超过哨兵值（未设置限额）或解析失败时返回 `None`
+#[cfg(target_os = "linux")]
+fn parse_cgroup_v1_memory(limit_path: &str, usage_path: &str) -> Option<u64> {
+    let limit: u64 = fs::read_to_string(limit_path).ok()?.trim().parse().ok()?;
+
+    if limit >= CGROUP_V1_MEMORY_UNLIMITED {
+        return None;
+    }
+
+    let usage: u64 = fs::read_to_string(usage_path).ok()?.trim().parse().ok()?;
+
+    Some(limit.saturating_sub(usage))
+}
+
+/// 获取容器（cgroup）限额下实际可用的内存，单位字节
+///
+/// cgroup v2 下取 `memory.max` 与 `memory.current` 的差值；cgroup v1 下取
+/// `memory.limit_in_bytes`（哨兵值视为未设置限额）与 `memory.usage_in_bytes`
+/// 的差值。未设置限额、非 Linux 平台或解析失败时回退到 `available_memory`。
+///
+/// # Examples
+///
+/// ```
+/// use sysinfo::{Components, Disks, Networks, System};
+/// let mut sys: System = System::new_all();
+/// let _ = collection_learning::cgroup_available_memory(&mut sys);
+/// ```
+#[cfg(target_os = "linux")]
+pub fn cgroup_available_memory(sys: &mut System) -> Result<u64, String> {
+    match detect_cgroup_version() {
+        Some(CgroupVersion::V2) => {
+            if let Some(mem) = parse_cgroup_v2_memory(
+                "/sys/fs/cgroup/memory.max",
+                "/sys/fs/cgroup/memory.current",
+            ) {
+                return Ok(mem);
+            }
+        }
+        Some(CgroupVersion::V1) => {
+            if let Some(mem) = parse_cgroup_v1_memory(
+                "/sys/fs/cgroup/memory/memory.limit_in_bytes",
+                "/sys/fs/cgroup/memory/memory.usage_in_bytes",
+            ) {
+                return Ok(mem);
+            }
+        }
+        None => {}
+    }
+
+    available_memory(sys)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn cgroup_available_memory(sys: &mut System) -> Result<u64, String> {
+    available_memory(sys)
+}
+
+
+/// 两次 CPU 刷新之间的最小间隔
+///
+/// sysinfo 在两次刷新间隔过短时会把 `cpu_usage()` 报告为 0%，因此至少
+/// 要等待这么久再刷新一次，才能拿到有意义的使用率。
+pub const MINIMUM_CPU_UPDATE_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 持有单个 `System`/`Disks` 句柄的有状态采集器
+///
+/// 本 crate 中的独立函数每次调用都会重新创建
+/// `Disks::new_with_refreshed_list()`，重复采集时代价较高；
+/// `availabe_num_cpus` 也只刷新一次 CPU 就读取 `cpu_usage()`，而
+/// sysinfo 在首次刷新后该值恒为 0%。`Collector` 复用同一份系统句柄，
+/// 并在两次 CPU 刷新之间等待 `MINIMUM_CPU_UPDATE_INTERVAL`，从而在反
+/// 复采样时得到准确的使用率。
+pub struct Collector {
+    sys: System,
+    disks: Disks,
+}
+
+impl Collector {
+    /// 创建一个新的 `Collector` 并完成首次 CPU 采样
+    pub fn new() -> Self {
+        let mut sys = System::new_all();
+        let disks = Disks::new_with_refreshed_list();
+
+        sys.refresh_cpu_all();
+        std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        sys.refresh_cpu_all();
+
+        Collector { sys, disks }
+    }
+
+    /// 刷新所有缓存的系统数据
+    ///
+    /// CPU 会刷新两次，中间等待 `MINIMUM_CPU_UPDATE_INTERVAL`，以便
+    /// `cpu_usage()` 反映真实使用率而不是固定的 0%。
+    pub fn refresh(&mut self) {
+        self.sys.refresh_cpu_all();
+        std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        self.sys.refresh_cpu_all();
+
+        self.sys.refresh_memory();
+        self.disks.refresh(true);
+    }
+
+    /// 本机 CPU 核数
+    pub fn total_num_cpus(&self) -> Result<usize, String> {
+        let cpus = self.sys.cpus();
+        if cpus.is_empty() {
+            Err("无法获取 CPU 信息，可能系统资源不足或数据未初始化".to_string())
+        } else {
+            Ok(cpus.len())
+        }
+    }
+
+    /// 本机可用 CPU 核数（使用率 <= 95% 视为可用）
+    ///
+    /// 调用 `new`/`refresh` 后已经完成一次符合
+    /// `MINIMUM_CPU_UPDATE_INTERVAL` 的二次采样，可直接得到有意义的结果。
+    pub fn available_num_cpus(&self) -> Result<usize, String> {
+        let cpus = self.sys.cpus();
+        if cpus.is_empty() {
+            return Err("无法获取 CPU 信息，可能系统资源不足或数据未初始化".to_string());
+        }
+
+        let allocatable_cpus = cpus
+            .iter()
+            .filter(|cpu| cpu.cpu_usage() <= 95.0) // CPU 使用率小于等于 95% 时可用
+            .count();
+
+        Ok(allocatable_cpus)
+    }
+
+    /// 本机内存总量，单位字节
+    pub fn total_memory(&self) -> Result<u64, String> {
+        let total_memory = self.sys.total_memory();
+        if total_memory == 0 {
+            Err("无法获取总内存信息，可能系统资源不足或数据未初始化".to_string())
+        } else {
+            Ok(total_memory)
+        }
+    }
+
+    /// 本机内存可用量，单位字节
+    pub fn available_memory(&self) -> Result<u64, String> {
+        let available_memory = self.sys.available_memory();
+        if available_memory == 0 {
+            Err("无法获取可用内存信息，可能系统资源不足或数据未初始化".to_string())
+        } else {
+            Ok(available_memory)
+        }
+    }
+
+    /// 本机磁盘总空间，单位字节
+    pub fn total_space(&self) -> Result<u64, String> {
+        if self.disks.is_empty() {
+            return Err("无法获取磁盘信息，磁盘列表为空".to_string());
+        }
+
+        Ok(self.disks.iter().map(|disk| disk.total_space()).sum())
+    }
+
+    /// 本机磁盘可用总空间，单位字节
+    pub fn available_space(&self) -> Result<u64, String> {
+        if self.disks.is_empty() {
+            return Err("无法获取磁盘信息，磁盘列表为空".to_string());
+        }
+
+        Ok(self.disks.iter().map(|disk| disk.available_space()).sum())
+    }
+
+    /// 每块磁盘的详细信息，参见独立函数 `disks_detail`
+    pub fn disks_detail(&self) -> Result<Vec<DiskInfo>, String> {
+        if self.disks.is_empty() {
+            return Err("无法获取磁盘信息，磁盘列表为空".to_string());
+        }
+
+        let details = self
+            .disks
+            .iter()
+            .map(|disk| DiskInfo {
+                name: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                file_system: disk.file_system().to_string_lossy().to_string(),
+                kind: disk.kind().into(),
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+                is_removable: disk.is_removable(),
+            })
+            .collect();
+
+        Ok(details)
+    }
+}
+
+impl Default for Collector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// 磁盘类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub enum DiskKind {
+    Ssd,
+    Hdd,
+    Unknown,
+}
+
+impl From<sysinfo::DiskKind> for DiskKind {
+    fn from(kind: sysinfo::DiskKind) -> Self {
+        match kind {
+            sysinfo::DiskKind::SSD => DiskKind::Ssd,
+            sysinfo::DiskKind::HDD => DiskKind::Hdd,
+            sysinfo::DiskKind::Unknown(_) => DiskKind::Unknown,
+        }
+    }
+}
+
+/// 单块磁盘的详细信息
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DiskInfo {
+    pub name: String,
+    pub mount_point: String,
+    pub file_system: String,
+    pub kind: DiskKind,
+    pub total_space: u64,
+    pub available_space: u64,
+    pub is_removable: bool,
+}
+
+/// 获取每块磁盘的详细信息
+///
+/// `total_space`/`available_space` 把所有磁盘汇总成一个数字，调用方既
+/// 无法区分虚拟盘、可移动盘，也无法单独计算某个挂载点的使用率。本函数
+/// 返回每块磁盘的名称、挂载点、文件系统（如 `EXT4`、`NTFS`）、类型
+/// （SSD/HDD/未知）、总空间、可用空间以及是否可移动，方便调用方自行
+/// 过滤、求和或找出最满的分区。
+///
+/// # Examples
+///
+/// ```
+/// use sysinfo::System;
+/// let mut sys: System = System::new_all();
+/// let _ = collection_learning::disks_detail(&mut sys);
+/// ```
+pub fn disks_detail(_sys: &mut System) -> Result<Vec<DiskInfo>, String> {
+    let disks: Disks = Disks::new_with_refreshed_list();
+
+    if disks.is_empty() {
+        return Err("无法获取磁盘信息，磁盘列表为空".to_string());
+    }
+
+    let details = disks
+        .iter()
+        .map(|disk| DiskInfo {
+            name: disk.name().to_string_lossy().to_string(),
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            file_system: disk.file_system().to_string_lossy().to_string(),
+            kind: disk.kind().into(),
+            total_space: disk.total_space(),
+            available_space: disk.available_space(),
+            is_removable: disk.is_removable(),
+        })
+        .collect();
+
+    Ok(details)
+}
+
+
+/// 获取本机交换空间总量，单位字节
+///
+/// 未启用交换空间是合法的配置，返回 `Ok(0)` 而非错误。
+///
+/// # Examples
+///
+/// ```
+/// use sysinfo::{Components, Disks, Networks, System};
+/// let mut sys: System = System::new_all();
+/// let _ = collection_learning::total_swap(&mut sys);
+/// ```
+pub fn total_swap(sys: &mut System) -> Result<u64, String> {
+    sys.refresh_memory();
+    Ok(sys.total_swap())
+}
+
+/// 获取本机已用交换空间，单位字节
+///
+/// 未启用交换空间是合法的配置，返回 `Ok(0)` 而非错误。
+///
+/// # Examples
+///
+/// ```
+/// use sysinfo::{Components, Disks, Networks, System};
+/// let mut sys: System = System::new_all();
+/// let _ = collection_learning::used_swap(&mut sys);
+/// ```
+pub fn used_swap(sys: &mut System) -> Result<u64, String> {
+    sys.refresh_memory();
+    Ok(sys.used_swap())
+}
+
+/// 获取本机可用交换空间，单位字节
+///
+/// 未启用交换空间是合法的配置，返回 `Ok(0)` 而非错误。
+///
+/// # Examples
+///
+/// ```
+/// use sysinfo::{Components, Disks, Networks, System};
+/// let mut sys: System = System::new_all();
+/// let _ = collection_learning::available_swap(&mut sys);
+/// ```
+pub fn available_swap(sys: &mut System) -> Result<u64, String> {
+    sys.refresh_memory();
+    Ok(sys.total_swap().saturating_sub(sys.used_swap()))
+}
+
+
+/// 一次性采集的全量指标快照，可直接序列化为 JSON 上报给监控系统
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Snapshot {
+    pub total_num_cpus: usize,
+    pub available_num_cpus: usize,
+    pub total_memory: u64,
+    pub available_memory: u64,
+    pub used_memory: u64,
+    pub total_swap: u64,
+    pub used_swap: u64,
+    pub available_swap: u64,
+    pub disks: Vec<DiskInfo>,
+}
+
+impl Snapshot {
+    /// 已用内存占总内存的百分比（0.0 ~ 100.0）
+    pub fn memory_percentage(&self) -> f64 {
+        if self.total_memory == 0 {
+            return 0.0;
+        }
+
+        (self.used_memory as f64 / self.total_memory as f64) * 100.0
+    }
+}
+
+/// 采样可用 CPU 核数（使用率 <= 95% 视为可用）
+///
+/// `availabe_num_cpus` 只刷新一次 CPU 就读取 `cpu_usage()`，sysinfo 在
+/// 首次刷新后该值恒为 0%。本函数按 `Collector` 的做法，在两次 CPU 刷
+/// 新之间等待 `MINIMUM_CPU_UPDATE_INTERVAL`，从而得到有意义的使用率。
+fn sampled_available_num_cpus(sys: &mut System) -> Result<usize, String> {
+    sys.refresh_cpu_all();
+    std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+    sys.refresh_cpu_all();
+
+    let cpus = sys.cpus();
+    if cpus.is_empty() {
+        return Err("无法获取 CPU 信息，可能系统资源不足或数据未初始化".to_string());
+    }
+
+    Ok(cpus.iter().filter(|cpu| cpu.cpu_usage() <= 95.0).count())
+}
+
+/// 一次性采集 CPU、内存、交换空间与磁盘的全部指标
+///
+/// 调用方此前需要分别调用 `total_num_cpus`、`availabe_num_cpus`、
+/// `total_memory`、`available_memory`、`total_swap`、`used_swap`、
+/// `available_swap`、`disks_detail` 八个函数并自行拼装结构体，才能
+/// 上报给监控代理或 HTTP 接口。`collect_snapshot` 把它们归并到一次调
+/// 用中，返回一个可直接 `serde_json::to_string` 的 `Snapshot`。
+///
+/// # Examples
+///
+/// ```
+/// use sysinfo::System;
+/// let mut sys: System = System::new_all();
+/// let _ = collection_learning::collect_snapshot(&mut sys);
+/// ```
+pub fn collect_snapshot(sys: &mut System) -> Result<Snapshot, String> {
+    let total_num_cpus = total_num_cpus(sys)?;
+    let available_num_cpus = sampled_available_num_cpus(sys)?;
+
+    let total_memory = total_memory(sys)?;
+    let available_memory = available_memory(sys)?;
+    sys.refresh_memory();
+    let used_memory = sys.used_memory();
+
+    let total_swap = total_swap(sys)?;
+    let used_swap = used_swap(sys)?;
+    let available_swap = available_swap(sys)?;
+
+    let disks = disks_detail(sys)?;
+
+    Ok(Snapshot {
+        total_num_cpus,
+        available_num_cpus,
+        total_memory,
+        available_memory,
+        used_memory,
+        total_swap,
+        used_swap,
+        available_swap,
+        disks,
+    })
+}
+
+
+/// 单个网络接口在采样区间内的收发字节数增量
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NetworkThroughput {
+    pub interface_name: String,
+    pub received: u64,
+    pub transmitted: u64,
+}
+
+/// 采集两次刷新之间各网络接口的收发字节数增量
+///
+/// 单次快照的收发总量意义不大，真正有用的是吞吐量，因此需要在
+/// `interval` 之后再刷新一次 `Networks`，用两次快照之间的差值得到区
+/// 间内的增量。`interval` 为零时两次刷新几乎同时发生，增量恒为 0。
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// let _ = collection_learning::network_throughput(Duration::from_millis(500));
+/// ```
+pub fn network_throughput(interval: Duration) -> Result<Vec<NetworkThroughput>, String> {
+    let mut networks = Networks::new_with_refreshed_list();
+    std::thread::sleep(interval);
+    networks.refresh(true);
+
+    if networks.is_empty() {
+        return Err("无法获取网络接口信息，接口列表为空".to_string());
+    }
+
+    let throughput = networks
+        .iter()
+        .map(|(interface_name, data)| NetworkThroughput {
+            interface_name: interface_name.clone(),
+            received: data.received(),
+            transmitted: data.transmitted(),
+        })
+        .collect();
+
+    Ok(throughput)
+}
+
+/// 单个温度传感器的读数
+///
+/// `temperature`/`max_temperature` 为 `None` 表示该传感器不支持此项读数，
+/// 与「读数为 0°C」是两码事，因此不在此处折叠成 0.0。
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ComponentTemperature {
+    pub label: String,
+    pub temperature: Option<f32>,
+    pub max_temperature: Option<f32>,
+}
+
+/// 采集所有温度传感器（如 CPU、主板）的标签与当前/历史最高温度
+///
+/// # Examples
+///
+/// ```
+/// let _ = collection_learning::component_temperatures();
+/// ```
+pub fn component_temperatures() -> Result<Vec<ComponentTemperature>, String> {
+    let components = Components::new_with_refreshed_list();
+
+    if components.is_empty() {
+        return Err("无法获取温度传感器信息，组件列表为空".to_string());
+    }
+
+    let temperatures = components
+        .iter()
+        .map(|component| ComponentTemperature {
+            label: component.label().to_string(),
+            temperature: component.temperature(),
+            max_temperature: component.max(),
+        })
+        .collect();
+
+    Ok(temperatures)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在系统临时目录下写入一个以名称、进程号和内容长度为后缀的文件，返回其路径
+    #[cfg(target_os = "linux")]
+    fn write_temp_file(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "collection_learning_test_{}_{}_{:?}",
+            name,
+            std::process::id(),
+            content.len()
+        ));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v2_cpu_max_computes_ceiling() {
+        let path = write_temp_file("cpu_max_limited", "150000 100000\n");
+        assert_eq!(parse_cgroup_v2_cpu_max(path.to_str().unwrap()), Some(2));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v2_cpu_max_unlimited_is_none() {
+        let path = write_temp_file("cpu_max_unlimited", "max 100000\n");
+        assert_eq!(parse_cgroup_v2_cpu_max(path.to_str().unwrap()), None);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v1_cpu_quota_computes_ceiling() {
+        let quota_path = write_temp_file("cfs_quota", "250000\n");
+        let period_path = write_temp_file("cfs_period", "100000\n");
+        assert_eq!(
+            parse_cgroup_v1_cpu_quota(
+                quota_path.to_str().unwrap(),
+                period_path.to_str().unwrap(),
+            ),
+            Some(3)
+        );
+        fs::remove_file(quota_path).unwrap();
+        fs::remove_file(period_path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v1_cpu_quota_negative_is_none() {
+        let quota_path = write_temp_file("cfs_quota_negative", "-1\n");
+        let period_path = write_temp_file("cfs_period_negative", "100000\n");
+        assert_eq!(
+            parse_cgroup_v1_cpu_quota(
+                quota_path.to_str().unwrap(),
+                period_path.to_str().unwrap(),
+            ),
+            None
+        );
+        fs::remove_file(quota_path).unwrap();
+        fs::remove_file(period_path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v2_memory_computes_difference() {
+        let max_path = write_temp_file("memory_max_limited", "1000000\n");
+        let current_path = write_temp_file("memory_current", "400000\n");
+        assert_eq!(
+            parse_cgroup_v2_memory(max_path.to_str().unwrap(), current_path.to_str().unwrap()),
+            Some(600000)
+        );
+        fs::remove_file(max_path).unwrap();
+        fs::remove_file(current_path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v2_memory_unlimited_is_none() {
+        let max_path = write_temp_file("memory_max_unlimited", "max\n");
+        let current_path = write_temp_file("memory_current_unlimited", "400000\n");
+        assert_eq!(
+            parse_cgroup_v2_memory(max_path.to_str().unwrap(), current_path.to_str().unwrap()),
+            None
+        );
+        fs::remove_file(max_path).unwrap();
+        fs::remove_file(current_path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v1_memory_computes_difference() {
+        let limit_path = write_temp_file("memory_limit", "1000000\n");
+        let usage_path = write_temp_file("memory_usage", "400000\n");
+        assert_eq!(
+            parse_cgroup_v1_memory(limit_path.to_str().unwrap(), usage_path.to_str().unwrap()),
+            Some(600000)
+        );
+        fs::remove_file(limit_path).unwrap();
+        fs::remove_file(usage_path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn parse_cgroup_v1_memory_unlimited_is_none() {
+        let limit_path = write_temp_file("memory_limit_unlimited", "9223372036854771712\n");
+        let usage_path = write_temp_file("memory_usage_unlimited", "400000\n");
+        assert_eq!(
+            parse_cgroup_v1_memory(limit_path.to_str().unwrap(), usage_path.to_str().unwrap()),
+            None
+        );
+        fs::remove_file(limit_path).unwrap();
+        fs::remove_file(usage_path).unwrap();
+    }
+
+    fn sample_snapshot(used_memory: u64, total_memory: u64) -> Snapshot {
+        Snapshot {
+            total_num_cpus: 0,
+            available_num_cpus: 0,
+            total_memory,
+            available_memory: total_memory.saturating_sub(used_memory),
+            used_memory,
+            total_swap: 0,
+            used_swap: 0,
+            available_swap: 0,
+            disks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn memory_percentage_computes_ratio() {
+        let snapshot = sample_snapshot(4_000_000_000, 16_000_000_000);
+        assert_eq!(snapshot.memory_percentage(), 25.0);
+    }
+
+    #[test]
+    fn memory_percentage_zero_total_is_zero() {
+        let snapshot = sample_snapshot(0, 0);
+        assert_eq!(snapshot.memory_percentage(), 0.0);
+    }
+}